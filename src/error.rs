@@ -3,65 +3,67 @@
 //!
 //! Error type for CIK parsing and building.
 
-use std::error::Error;
-use std::fmt::Formatter;
-use std::fmt::{Debug, Display};
+use core::fmt::Formatter;
+use core::fmt::{Debug, Display};
 
 /// All the ways parsing or building could fail.
 #[non_exhaustive]
 #[derive(Clone, PartialEq, Eq)]
 pub enum CIKError {
-    /// The input length is not 1 to 10 bytes.
-    InvalidLength {
-        /// The length we found
-        was: usize,
+    /// The input was empty.
+    Empty,
+    /// The input was longer than the 10 bytes a CIK can hold.
+    TooLong {
+        /// The length we found, in bytes.
+        len: usize,
     },
-    /// The input does not parse as an integer.
-    InvalidFormat {
-        /// The input string
-        was: String,
+    /// The input contained a byte that was not an ASCII digit.
+    InvalidDigit {
+        /// The offending byte.
+        byte: u8,
+        /// The zero-based byte offset at which it was found.
+        pos: usize,
     },
     /// The value is not a positive number of up to 10 digits (checked when building).
-    InvalidValue {
-        /// The length we found
-        was: u64,
+    OutOfRange {
+        /// The value we found.
+        value: u64,
     },
 }
 
 impl Debug for CIKError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
-            CIKError::InvalidLength { was } => {
-                write!(f, "InvalidLength {{ was: {:?} }}", was)
-            }
-            CIKError::InvalidFormat { was } => {
-                write!(f, "InvalidFormat {{ was: {:?} }}", was)
-            }
-            CIKError::InvalidValue { was } => {
-                write!(f, "InvalidValue {{ was: {:?} }}", was)
+            CIKError::Empty => write!(f, "Empty"),
+            CIKError::TooLong { len } => write!(f, "TooLong {{ len: {:?} }}", len),
+            CIKError::InvalidDigit { byte, pos } => {
+                write!(f, "InvalidDigit {{ byte: {:?}, pos: {:?} }}", byte, pos)
             }
+            CIKError::OutOfRange { value } => write!(f, "OutOfRange {{ value: {:?} }}", value),
         }
     }
 }
 
 impl Display for CIKError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
-            CIKError::InvalidLength { was } => {
-                write!(f, "invalid length {} bytes when expecting 1 to 10", was)
-            }
-            CIKError::InvalidFormat { was } => {
-                write!(f, "invalid format {} when expecting integer", was)
-            }
-            CIKError::InvalidValue { was } => {
-                write!(
-                    f,
-                    "invalid value {} when expecting positive number up to 9,999,999,999",
-                    was
-                )
+            CIKError::Empty => write!(f, "input was empty"),
+            CIKError::TooLong { len } => {
+                write!(f, "input was {} bytes long when expecting at most 10", len)
             }
+            CIKError::InvalidDigit { byte, pos } => write!(
+                f,
+                "invalid digit {:#04x} at byte position {} when expecting an ASCII digit",
+                byte, pos
+            ),
+            CIKError::OutOfRange { value } => write!(
+                f,
+                "value {} out of range when expecting a positive number up to 9,999,999,999",
+                value
+            ),
         }
     }
 }
 
-impl Error for CIKError {}
+#[cfg(feature = "std")]
+impl std::error::Error for CIKError {}
@@ -29,33 +29,108 @@
 //! * [ISIN](https://crates.io/crates/isin): International Securities Identification Number (ISO 6166:2021)
 //! * [LEI](https://crates.io/crates/lei): Legal Entity Identifier (ISO 17442:2020)
 //!
+//! ## `no_std`
+//!
+//! This crate is `no_std`, relying only on `alloc` for the owned `String` returned by
+//! [`CIK::to_padded_string`]. The default-on `std` feature additionally provides the `Error`
+//! trait impl on [`CIKError`]; disable default features to build without it.
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use std::fmt;
-use std::str::FromStr;
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+use core::str::FromStr;
 
 pub mod error;
 pub use error::CIKError;
 
+#[cfg(feature = "serde")]
+pub mod serde;
+
 /// Parse a string to a valid CIK or an error message, requiring the string to already be only
 /// digits with no leading or trailing whitespace in addition to being the
 /// right length and format.
 pub fn parse(value: &str) -> Result<CIK, CIKError> {
-    let s: String = value.into();
+    parse_bytes(value.as_bytes())
+}
 
-    if s.is_empty() || s.len() > 10 {
-        Err(CIKError::InvalidLength { was: s.len() })
+/// Parse a string to a valid CIK, first stripping an optional case-insensitive `CIK` prefix of
+/// the kind found in EDGAR URLs and filenames (`CIK0000320193`), but otherwise applying the same
+/// strict rules as [`parse`]. Only that prefix is removed; other path text such as
+/// `edgar/data/320193/...` is not accepted.
+pub fn parse_prefixed(value: &str) -> Result<CIK, CIKError> {
+    parse(strip_cik_prefix(value))
+}
+
+/// Strip a leading case-insensitive `CIK` prefix, if present.
+fn strip_cik_prefix(value: &str) -> &str {
+    let bytes = value.as_bytes();
+
+    if bytes.len() >= 3 && bytes[..3].eq_ignore_ascii_case(b"CIK") {
+        &value[3..]
     } else {
-        match s.parse::<u64>() {
-            Ok(value) => build(value),
-            Err(_err) => Err(CIKError::InvalidFormat { was: s }),
+        value
+    }
+}
+
+/// Parse a string to a valid CIK, tolerating the sloppy formatting real-world data feeds tend to
+/// use: surrounding ASCII whitespace, an optional case-insensitive `CIK` prefix, and zero-padding
+/// beyond the usual 10-character width, so long as at most 10 significant digits remain (so
+/// `" 0000320193 "`, `"CIK320193"`, and `"320193"` all yield the same [`CIK`]).
+///
+/// Unlike [`parse`], which stays strict about shape, `parse_loose` is meant for cleaning up CIKs
+/// pulled from spreadsheets, CSV exports, and other sloppy data feeds.
+pub fn parse_loose(value: &str) -> Result<CIK, CIKError> {
+    let trimmed = value.trim_matches(|c: char| c.is_ascii_whitespace());
+    let unprefixed = strip_cik_prefix(trimmed);
+    let significant = unprefixed.trim_start_matches('0');
+    let digits = if significant.is_empty() { "0" } else { significant };
+
+    parse(digits)
+}
+
+/// Parse a byte slice directly to a valid CIK without requiring it to be valid UTF-8 first.
+///
+/// Much financial data arrives as ASCII byte slices straight from CSV or fixed-width files, so
+/// this accumulates the integer value digit by digit, with no intermediate `str::from_utf8` or
+/// `u64::from_str` step. [`parse`] delegates to this function on the string's bytes.
+pub fn parse_bytes(value: &[u8]) -> Result<CIK, CIKError> {
+    if value.is_empty() {
+        return Err(CIKError::Empty);
+    }
+    if value.len() > 10 {
+        return Err(CIKError::TooLong { len: value.len() });
+    }
+
+    let mut accumulator: u64 = 0;
+    for (pos, &byte) in value.iter().enumerate() {
+        if !byte.is_ascii_digit() {
+            return Err(CIKError::InvalidDigit { byte, pos });
         }
+        accumulator = accumulator * 10 + u64::from(byte - b'0');
     }
+
+    build(accumulator)
+}
+
+/// Test whether or not the passed byte slice is in valid CIK format, without producing a CIK
+/// struct value or requiring the bytes to be valid UTF-8.
+pub fn validate_bytes(value: &[u8]) -> bool {
+    if value.is_empty() || value.len() > 10 {
+        return false;
+    }
+
+    value.iter().all(|b| b.is_ascii_digit())
 }
 
 /// Build a CIK from an integer _Value_.
 pub fn build(value: u64) -> Result<CIK, CIKError> {
     if !(1..=9_999_999_999).contains(&value) {
-        return Err(CIKError::InvalidValue { was: value });
+        return Err(CIKError::OutOfRange { value });
     }
 
     Ok(CIK(value))
@@ -64,18 +139,7 @@ pub fn build(value: u64) -> Result<CIK, CIKError> {
 /// Test whether or not the passed string is in valid CIK format, without producing a CIK struct
 /// value.
 pub fn validate(value: &str) -> bool {
-    if value.is_empty() || value.len() > 10 {
-        println!("Bad length: {:?}", value);
-        return false;
-    }
-
-    // We make the preliminary assumption that the string is pure ASCII, so we work with the
-    // underlying bytes. If there is Unicode in the string, the bytes will be outside the
-    // allowed range and format validation will fail.
-
-    let b = value.as_bytes();
-
-    return b.iter().all(|b| *b >= b'0' && *b <= b'9');
+    validate_bytes(value.as_bytes())
 }
 
 #[doc = include_str!("../README.md")]
@@ -97,7 +161,11 @@ pub struct CIK(u64);
 
 impl fmt::Display for CIK {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        if f.alternate() {
+            write!(f, "{:010}", self.0)
+        } else {
+            write!(f, "{}", self.0)
+        }
     }
 }
 
@@ -128,6 +196,19 @@ impl CIK {
     pub fn value(&self) -> u64 {
         self.0
     }
+
+    /// Render the CIK in the EDGAR-canonical fixed-width, zero-padded form, e.g. `"0000320193"`.
+    ///
+    /// This is the same form produced by the alternate `Display` form (`format!("{:#}", cik)`),
+    /// provided as a named method for callers who would rather not format it themselves.
+    pub fn to_padded_string(&self) -> String {
+        format!("{:010}", self.0)
+    }
+
+    /// See [`parse_loose`].
+    pub fn parse_loose(value: &str) -> Result<CIK, CIKError> {
+        parse_loose(value)
+    }
 }
 
 #[cfg(test)]
@@ -142,7 +223,7 @@ mod tests {
                 assert_eq!(cik.to_string(), "320193");
                 assert_eq!(cik.value(), 320193);
             }
-            Err(err) => assert!(false, "Did not expect parsing to fail: {}", err),
+            Err(err) => panic!("Did not expect parsing to fail: {}", err),
         }
     }
     #[test]
@@ -152,7 +233,7 @@ mod tests {
                 assert_eq!(cik.to_string(), "320193");
                 assert_eq!(cik.value(), 320193);
             }
-            Err(err) => assert!(false, "Did not expect building to fail: {}", err),
+            Err(err) => panic!("Did not expect building to fail: {}", err),
         }
     }
 
@@ -192,6 +273,119 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[test]
+    fn to_padded_string_for_apple() {
+        let cik = build(320193).unwrap();
+        assert_eq!(cik.to_padded_string(), "0000320193");
+    }
+
+    #[test]
+    fn alternate_display_matches_to_padded_string() {
+        let cik = build(320193).unwrap();
+        assert_eq!(format!("{:#}", cik), cik.to_padded_string());
+    }
+
+    #[test]
+    fn parse_prefixed_strips_cik_prefix_case_insensitively() {
+        let expected = build(320193).unwrap();
+        assert_eq!(parse_prefixed("CIK0000320193").unwrap(), expected);
+        assert_eq!(parse_prefixed("cik0000320193").unwrap(), expected);
+        assert_eq!(parse_prefixed("0000320193").unwrap(), expected);
+    }
+
+    #[test]
+    fn parse_prefixed_still_rejects_garbage() {
+        let res = parse_prefixed("CIKabc");
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn parse_loose_tolerates_whitespace_prefix_and_padding() {
+        let expected = build(320193).unwrap();
+        assert_eq!(parse_loose(" 0000320193 ").unwrap(), expected);
+        assert_eq!(parse_loose("CIK320193").unwrap(), expected);
+        assert_eq!(parse_loose("cik0000320193").unwrap(), expected);
+        assert_eq!(parse_loose("320193").unwrap(), expected);
+        assert_eq!(parse_loose("  CIK00000320193  ").unwrap(), expected);
+    }
+
+    #[test]
+    fn parse_loose_still_rejects_garbage() {
+        let res = parse_loose("CIK abc");
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn parse_loose_only_trims_ascii_whitespace() {
+        // U+00A0 NO-BREAK SPACE is whitespace but not ASCII whitespace, so it should not be
+        // trimmed and parsing should fail rather than silently succeed.
+        let res = parse_loose("\u{00A0}320193\u{00A0}");
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn cik_parse_loose_matches_free_function() {
+        assert_eq!(CIK::parse_loose("CIK320193").unwrap(), parse_loose("320193").unwrap());
+    }
+
+    #[test]
+    fn parse_bytes_for_apple() {
+        let cik = parse_bytes(b"320193").unwrap();
+        assert_eq!(cik, build(320193).unwrap());
+    }
+
+    #[test]
+    fn parse_bytes_rejects_non_digit_bytes() {
+        let res = parse_bytes(b"32a193");
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn parse_bytes_rejects_empty_and_long_input() {
+        assert!(parse_bytes(b"").is_err());
+        assert!(parse_bytes(b"10000000000").is_err());
+    }
+
+    #[test]
+    fn validate_bytes_for_apple() {
+        assert!(validate_bytes(b"320193"));
+        assert!(!validate_bytes(b"32a193"));
+        assert!(!validate_bytes(b""));
+        assert!(!validate_bytes(b"10000000000"));
+    }
+
+    #[test]
+    fn empty_string_is_distinguished_from_zero_value() {
+        assert_eq!(parse(""), Err(CIKError::Empty));
+        assert_eq!(parse("0"), Err(CIKError::OutOfRange { value: 0 }));
+    }
+
+    #[test]
+    fn too_long_reports_the_length_found() {
+        assert_eq!(
+            parse("10000000000"),
+            Err(CIKError::TooLong { len: 11 })
+        );
+    }
+
+    #[test]
+    fn invalid_digit_reports_the_byte_and_position() {
+        assert_eq!(
+            parse("32a193"),
+            Err(CIKError::InvalidDigit { byte: b'a', pos: 2 })
+        );
+    }
+
+    #[test]
+    fn out_of_range_reports_the_value_found() {
+        assert_eq!(
+            build(10_000_000_000),
+            Err(CIKError::OutOfRange {
+                value: 10_000_000_000
+            })
+        );
+    }
+
     proptest! {
         #[test]
         #[allow(unused_must_use)]
@@ -1,12 +1,22 @@
 //! # cik::serde
 //!
 //! Serde support for CIKs.
+//!
+//! The top-level [`Serialize`]/[`Deserialize`] impls on [`CIK`] are deliberately permissive:
+//! they serialize to a bare integer and deserialize from either an integer or a string. Fields
+//! that need a specific wire format instead can opt into one of the submodules below with
+//! `#[serde(with = "...")]`:
+//!
+//! * [`padded`] always serializes to the EDGAR-canonical 10-digit zero-padded string, e.g.
+//!   `"0000320193"`, and deserializes from either a padded or unpadded digit string.
+//! * [`integer`] always serializes and deserializes as a plain `u64`, rejecting string input.
 
 use self::cik::CIK;
 use crate as cik;
+use alloc::format;
+use core::fmt;
 use serde::de::{self, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::fmt;
 
 impl Serialize for cik::CIK {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -70,10 +80,98 @@ impl<'de> Deserialize<'de> for CIK {
     }
 }
 
+/// Serialize to, and deserialize from, the EDGAR-canonical 10-digit zero-padded string form
+/// (e.g. `"0000320193"`).
+///
+/// Use with `#[serde(with = "cik::serde::padded")]` on a `CIK` field.
+pub mod padded {
+    use super::cik::{self, CIK};
+    use alloc::format;
+    use core::fmt;
+    use serde::de::{self, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    /// Serialize a [`CIK`] as its 10-digit zero-padded string form.
+    pub fn serialize<S>(value: &CIK, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&value.to_padded_string())
+    }
+
+    struct PaddedVisitor;
+    impl<'de> Visitor<'de> for PaddedVisitor {
+        type Value = CIK;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a zero-padded or unpadded CIK string")
+        }
+
+        fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            cik::parse(s).map_err(|err| E::custom(format!("Cannot deserialize {}: {}", s, err)))
+        }
+    }
+
+    /// Deserialize a [`CIK`] from either its padded or unpadded string form.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<CIK, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(PaddedVisitor)
+    }
+}
+
+/// Serialize to, and deserialize from, a plain `u64`, rejecting string input.
+///
+/// Use with `#[serde(with = "cik::serde::integer")]` on a `CIK` field.
+pub mod integer {
+    use super::cik::{self, CIK};
+    use alloc::format;
+    use core::fmt;
+    use serde::de::{self, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    /// Serialize a [`CIK`] as a plain `u64`.
+    pub fn serialize<S>(value: &CIK, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(value.value())
+    }
+
+    struct IntegerVisitor;
+    impl<'de> Visitor<'de> for IntegerVisitor {
+        type Value = CIK;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a positive integer up to 10 digits")
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            cik::build(value).map_err(|err| E::custom(format!("Cannot deserialize {}: {}", value, err)))
+        }
+    }
+
+    /// Deserialize a [`CIK`] from a plain `u64`.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<CIK, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_u64(IntegerVisitor)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
     // use crate::CIK;
+    use ::serde::{Deserialize, Serialize};
     use serde_json;
 
     #[test]
@@ -106,4 +204,53 @@ mod tests {
         println!("deserialized = {}", deserialized);
         assert_eq!(deserialized, test_cik);
     }
+
+    #[derive(Serialize, Deserialize)]
+    struct PaddedWrapper {
+        #[serde(with = "crate::serde::padded")]
+        cik: CIK,
+    }
+
+    #[test]
+    fn padded_serializes_zero_padded() {
+        let wrapper = PaddedWrapper {
+            cik: build(320193).unwrap(),
+        };
+
+        let serialized = serde_json::to_string(&wrapper).unwrap();
+        assert_eq!(serialized, r#"{"cik":"0000320193"}"#);
+    }
+
+    #[test]
+    fn padded_deserializes_padded_or_unpadded() {
+        let test_cik: CIK = build(320193).unwrap();
+
+        let from_padded: PaddedWrapper = serde_json::from_str(r#"{"cik":"0000320193"}"#).unwrap();
+        assert_eq!(from_padded.cik, test_cik);
+
+        let from_unpadded: PaddedWrapper = serde_json::from_str(r#"{"cik":"320193"}"#).unwrap();
+        assert_eq!(from_unpadded.cik, test_cik);
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct IntegerWrapper {
+        #[serde(with = "crate::serde::integer")]
+        cik: CIK,
+    }
+
+    #[test]
+    fn integer_serializes_as_number() {
+        let wrapper = IntegerWrapper {
+            cik: build(320193).unwrap(),
+        };
+
+        let serialized = serde_json::to_string(&wrapper).unwrap();
+        assert_eq!(serialized, r#"{"cik":320193}"#);
+    }
+
+    #[test]
+    fn integer_rejects_string_input() {
+        let result: Result<IntegerWrapper, _> = serde_json::from_str(r#"{"cik":"320193"}"#);
+        assert!(result.is_err());
+    }
 }